@@ -0,0 +1,84 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Reads `colors.txt` (one `name hex` pair per line) and emits a sorted
+/// name -> Color table plus a reverse Color -> nearest-name helper, so the
+/// canonical named-color list lives in a single editable data file instead
+/// of a hand-maintained `match`.
+fn main() {
+    println!("cargo:rerun-if-changed=colors.txt");
+
+    let data = fs::read_to_string("colors.txt").expect("failed to read colors.txt");
+    let mut entries: Vec<(String, u8, u8, u8, u8)> = data
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+            let name = parts
+                .next()
+                .unwrap_or_else(|| panic!("malformed colors.txt line: '{line}'"))
+                .to_string();
+            let hex = parts
+                .next()
+                .unwrap_or_else(|| panic!("malformed colors.txt line: '{line}'"));
+            let channel = |s: &str| u8::from_str_radix(s, 16).expect("invalid hex channel");
+            let r = channel(&hex[0..2]);
+            let g = channel(&hex[2..4]);
+            let b = channel(&hex[4..6]);
+            let a = if hex.len() >= 8 { channel(&hex[6..8]) } else { 0xff };
+            (name, r, g, b, a)
+        })
+        .collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut out = String::new();
+    out.push_str("/// Generated from `colors.txt` by build.rs. Do not edit by hand.\n");
+    out.push_str("static NAMED_COLORS: &[(&str, u8, u8, u8, u8)] = &[\n");
+    for (name, r, g, b, a) in &entries {
+        out.push_str(&format!(
+            "    (\"{name}\", {r}, {g}, {b}, {a}),\n"
+        ));
+    }
+    out.push_str("];\n\n");
+
+    out.push_str(
+        "pub(super) fn parse_named_color(name: &str) -> Option<Color> {\n\
+         \x20   NAMED_COLORS\n\
+         \x20       .binary_search_by(|(n, ..)| (*n).cmp(name))\n\
+         \x20       .ok()\n\
+         \x20       .map(|i| {\n\
+         \x20           let (_, r, g, b, a) = NAMED_COLORS[i];\n\
+         \x20           Color::srgba_u8(r, g, b, a)\n\
+         \x20       })\n\
+         }\n\n",
+    );
+
+    out.push_str(
+        "/// Finds the named color whose RGBA is closest to `color`, for\n\
+         /// `get_hex`-style debugging output.\n\
+         pub fn nearest_named_color(color: Color) -> &'static str {\n\
+         \x20   let srgba = color.to_srgba();\n\
+         \x20   let r = (srgba.red * 255.0).round() as i32;\n\
+         \x20   let g = (srgba.green * 255.0).round() as i32;\n\
+         \x20   let b = (srgba.blue * 255.0).round() as i32;\n\
+         \x20   let a = (srgba.alpha * 255.0).round() as i32;\n\
+         \x20   NAMED_COLORS\n\
+         \x20       .iter()\n\
+         \x20       .min_by_key(|(_, cr, cg, cb, ca)| {\n\
+         \x20           let dr = r - *cr as i32;\n\
+         \x20           let dg = g - *cg as i32;\n\
+         \x20           let db = b - *cb as i32;\n\
+         \x20           let da = a - *ca as i32;\n\
+         \x20           dr * dr + dg * dg + db * db + da * da\n\
+         \x20       })\n\
+         \x20       .map(|(name, ..)| *name)\n\
+         \x20       .unwrap_or(\"black\")\n\
+         }\n",
+    );
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
+    fs::write(Path::new(&out_dir).join("named_colors.rs"), out)
+        .expect("failed to write named_colors.rs");
+}
@@ -2,6 +2,12 @@ use bevy::prelude::Color;
 
 use crate::ElementsError;
 
+use bevy::prelude::{Entity, Parent, Query};
+
+use super::flavor::parse_flavor_color;
+use super::palette::{parse_role_color, parse_role_color_for_entity, DefaultPalette, Palette};
+use super::theme::parse_var_color;
+
 pub trait ColorFromHexExtension {
     fn from_hex<T: AsRef<str>>(color: T) -> Color {
         let color = color.as_ref().trim().trim_start_matches('#');
@@ -13,14 +19,34 @@ pub trait ColorFromHexExtension {
     }
     fn get_hex(&self) -> String;
     fn set_hex(&mut self, hex: impl AsRef<str>);
+
+    /// Linearly interpolates each sRGB component (and alpha) towards
+    /// `other` by `t`, where `t` is typically in `0.0..=1.0`.
+    fn mix(&self, other: Color, t: f32) -> Color;
+    /// Raises the color's lightness (in HSL space) by `amount`, clamped to
+    /// `0.0..=1.0`.
+    fn lighten(&self, amount: f32) -> Color;
+    /// Lowers the color's lightness (in HSL space) by `amount`, clamped to
+    /// `0.0..=1.0`.
+    fn darken(&self, amount: f32) -> Color;
+    /// Returns a copy of the color with its alpha channel replaced.
+    fn with_alpha(&self, a: f32) -> Color;
+    /// Returns the color's `(hue, saturation, lightness, alpha)` in HSL
+    /// space, with hue in degrees (`0.0..360.0`).
+    fn get_hsl(&self) -> (f32, f32, f32, f32);
+    /// Sets the color from HSL components, with hue in degrees.
+    fn set_hsl(&mut self, h: f32, s: f32, l: f32);
+    /// Returns `get_hex()` annotated with the closest CSS named color, for
+    /// debugging output (e.g. `"#ff6347 (~tomato)"`).
+    fn debug_hex(&self) -> String;
 }
 impl ColorFromHexExtension for Color {
     fn get_hex(&self) -> String {
         let srgba = self.to_srgba();
-        let r = (srgba.red * 256.0) as u8;
-        let g = (srgba.green * 256.0) as u8;
-        let b = (srgba.blue * 256.0) as u8;
-        let a = (srgba.alpha * 256.0) as u8;
+        let r = (srgba.red * 255.0).round() as u8;
+        let g = (srgba.green * 255.0).round() as u8;
+        let b = (srgba.blue * 255.0).round() as u8;
+        let a = (srgba.alpha * 255.0).round() as u8;
         if a == 255 {
             format!("#{r:02x}{g:02x}{b:02x}")
         } else {
@@ -30,6 +56,219 @@ impl ColorFromHexExtension for Color {
     fn set_hex(&mut self, hex: impl AsRef<str>) {
         *self = Self::from_hex(hex);
     }
+
+    fn mix(&self, other: Color, t: f32) -> Color {
+        let a = self.to_srgba();
+        let b = other.to_srgba();
+        Color::srgba(
+            a.red + (b.red - a.red) * t,
+            a.green + (b.green - a.green) * t,
+            a.blue + (b.blue - a.blue) * t,
+            a.alpha + (b.alpha - a.alpha) * t,
+        )
+    }
+
+    fn lighten(&self, amount: f32) -> Color {
+        let (h, s, l, a) = self.get_hsl();
+        let mut color = Color::WHITE;
+        color.set_hsl(h, s, (l + amount).clamp(0.0, 1.0));
+        color.with_alpha(a)
+    }
+
+    fn darken(&self, amount: f32) -> Color {
+        self.lighten(-amount)
+    }
+
+    fn with_alpha(&self, a: f32) -> Color {
+        let srgba = self.to_srgba();
+        Color::srgba(srgba.red, srgba.green, srgba.blue, a)
+    }
+
+    fn get_hsl(&self) -> (f32, f32, f32, f32) {
+        let srgba = self.to_srgba();
+        let (r, g, b, a) = (srgba.red, srgba.green, srgba.blue, srgba.alpha);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        if (max - min).abs() < f32::EPSILON {
+            return (0.0, 0.0, l, a);
+        }
+        let d = max - min;
+        let s = if l > 0.5 { d / (2.0 - max - min) } else { d / (max + min) };
+        let h = if max == r {
+            ((g - b) / d) % 6.0
+        } else if max == g {
+            (b - r) / d + 2.0
+        } else {
+            (r - g) / d + 4.0
+        };
+        let h = (h * 60.0).rem_euclid(360.0);
+        (h, s, l, a)
+    }
+
+    fn set_hsl(&mut self, h: f32, s: f32, l: f32) {
+        let a = self.to_srgba().alpha;
+        *self = hsl_to_color(h.rem_euclid(360.0), s.clamp(0.0, 1.0), l.clamp(0.0, 1.0), a);
+    }
+
+    fn debug_hex(&self) -> String {
+        format!("{} (~{})", self.get_hex(), nearest_named_color(*self))
+    }
+}
+
+/// Parses a color from any of the supported notations: `#hex`, CSS named
+/// colors, the CSS functional notations `rgb()`, `rgba()`, `hsl()` and
+/// `hsla()`, or the theming tokens `role(...)` (against the process-wide
+/// fallback palette), `var(...)` and `flavor(...)`.
+pub fn parse_color(input: &str) -> Result<Color, ElementsError> {
+    let input = input.trim();
+    if let Some(open) = input.find('(') {
+        let name = input[..open].trim().to_lowercase();
+        let close = input.rfind(')').ok_or_else(|| {
+            ElementsError::InvalidPropertyValue(format!("Can't parse color from '{input}'"))
+        })?;
+        let args = &input[open + 1..close];
+        return match name.as_str() {
+            "rgb" | "rgba" => parse_rgb_function(args),
+            "hsl" | "hsla" => parse_hsl_function(args),
+            "role" => parse_role_color(args),
+            "var" => parse_var_color(args),
+            "flavor" => parse_flavor_color(args),
+            _ => Err(ElementsError::InvalidPropertyValue(format!(
+                "Can't parse color from '{input}'"
+            ))),
+        };
+    }
+    let hex = input.trim_start_matches('#');
+    if let Ok(color) = parse_hex_color(hex) {
+        return Ok(color);
+    }
+    parse_named_color(input).ok_or_else(|| {
+        ElementsError::InvalidPropertyValue(format!("Can't parse color from '{input}'"))
+    })
+}
+
+/// Entity-aware variant of [`parse_color`]: resolves `role(...)` tokens
+/// against `entity`'s effective [`Palette`] (walking its ancestors, see
+/// `palette::resolve_role`) instead of the process-wide fallback palette
+/// that [`parse_color`] uses. All other notations behave identically to
+/// [`parse_color`].
+pub fn parse_color_for_entity(
+    input: &str,
+    entity: Entity,
+    palettes: &Query<&Palette>,
+    parents: &Query<&Parent>,
+    default_palette: &DefaultPalette,
+) -> Result<Color, ElementsError> {
+    let trimmed = input.trim();
+    if let Some(open) = trimmed.find('(') {
+        let name = trimmed[..open].trim().to_lowercase();
+        if name == "role" {
+            let close = trimmed.rfind(')').ok_or_else(|| {
+                ElementsError::InvalidPropertyValue(format!("Can't parse color from '{trimmed}'"))
+            })?;
+            let args = &trimmed[open + 1..close];
+            return parse_role_color_for_entity(args, entity, palettes, parents, default_palette);
+        }
+    }
+    parse_color(input)
+}
+
+fn split_color_args(args: &str) -> Vec<&str> {
+    if args.contains(',') {
+        args.split(',').map(|a| a.trim()).collect()
+    } else {
+        // modern `r g b / a` syntax
+        args.split('/').flat_map(|part| part.split_whitespace()).collect()
+    }
+}
+
+fn parse_channel(value: &str) -> Result<u8, ElementsError> {
+    let err = || ElementsError::InvalidPropertyValue(format!("Can't parse color channel '{value}'"));
+    if let Some(percent) = value.strip_suffix('%') {
+        let percent: f32 = percent.trim().parse().map_err(|_| err())?;
+        if !(0.0..=100.0).contains(&percent) {
+            return Err(err());
+        }
+        Ok((percent / 100.0 * 255.0).round() as u8)
+    } else {
+        let channel: f32 = value.trim().parse().map_err(|_| err())?;
+        if !(0.0..=255.0).contains(&channel) {
+            return Err(err());
+        }
+        Ok(channel.round() as u8)
+    }
+}
+
+fn parse_alpha(value: &str) -> Result<f32, ElementsError> {
+    let err = || ElementsError::InvalidPropertyValue(format!("Can't parse alpha value '{value}'"));
+    if let Some(percent) = value.strip_suffix('%') {
+        let percent: f32 = percent.trim().parse().map_err(|_| err())?;
+        if !(0.0..=100.0).contains(&percent) {
+            return Err(err());
+        }
+        Ok(percent / 100.0)
+    } else {
+        let alpha: f32 = value.trim().parse().map_err(|_| err())?;
+        if !(0.0..=1.0).contains(&alpha) {
+            return Err(err());
+        }
+        Ok(alpha)
+    }
+}
+
+fn parse_rgb_function(args: &str) -> Result<Color, ElementsError> {
+    let parts = split_color_args(args);
+    let err = || ElementsError::InvalidPropertyValue(format!("Can't parse color from 'rgb({args})'"));
+    if parts.len() != 3 && parts.len() != 4 {
+        return Err(err());
+    }
+    let r = parse_channel(parts[0])?;
+    let g = parse_channel(parts[1])?;
+    let b = parse_channel(parts[2])?;
+    let a = if parts.len() == 4 {
+        (parse_alpha(parts[3])? * 255.0).round() as u8
+    } else {
+        255
+    };
+    Ok(Color::srgba_u8(r, g, b, a))
+}
+
+fn parse_hsl_function(args: &str) -> Result<Color, ElementsError> {
+    let parts = split_color_args(args);
+    let err = || ElementsError::InvalidPropertyValue(format!("Can't parse color from 'hsl({args})'"));
+    if parts.len() != 3 && parts.len() != 4 {
+        return Err(err());
+    }
+    let h: f32 = parts[0]
+        .trim()
+        .trim_end_matches("deg")
+        .trim()
+        .parse()
+        .map_err(|_| err())?;
+    let h = h.rem_euclid(360.0);
+    let s = parts[1].strip_suffix('%').ok_or_else(err)?.trim().parse::<f32>().map_err(|_| err())? / 100.0;
+    let l = parts[2].strip_suffix('%').ok_or_else(err)?.trim().parse::<f32>().map_err(|_| err())? / 100.0;
+    if !(0.0..=1.0).contains(&s) || !(0.0..=1.0).contains(&l) {
+        return Err(err());
+    }
+    let a = if parts.len() == 4 { parse_alpha(parts[3])? } else { 1.0 };
+    Ok(hsl_to_color(h, s, l, a))
+}
+
+fn hsl_to_color(h: f32, s: f32, l: f32, a: f32) -> Color {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = l - c / 2.0;
+    let (r1, g1, b1) = match h as u32 {
+        0..=59 => (c, x, 0.0),
+        60..=119 => (x, c, 0.0),
+        120..=179 => (0.0, c, x),
+        180..=239 => (0.0, x, c),
+        240..=299 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    Color::srgba(r1 + m, g1 + m, b1 + m, a)
 }
 
 pub(super) fn parse_hex_color(hex: &str) -> Result<Color, ElementsError> {
@@ -51,169 +290,171 @@ pub(super) fn parse_hex_color(hex: &str) -> Result<Color, ElementsError> {
     }
 }
 
-// Source: https://developer.mozilla.org/en-US/docs/Web/CSS/named-color
-
-/// Parses a named color, like "silver" or "azure" into a [`Color`]
+/// Parses a named color, like "silver" or "azure" into a [`Color`].
 ///
 /// Accepts any [valid CSS named-colors](https://developer.mozilla.org/en-US/docs/Web/CSS/named-color).
-pub(super) fn parse_named_color(name: &str) -> Option<Color> {
-    match name {
-        // CSS Level 1 values
-        "black" => Some(Color::srgba(0.0000, 0.0000, 0.0000, 1.0000)),
-        "silver" => Some(Color::srgba(0.7529, 0.7529, 0.7529, 1.0000)),
-        "gray" => Some(Color::srgba(0.5020, 0.5020, 0.5020, 1.0000)),
-        "white" => Some(Color::srgba(1.0000, 1.0000, 1.0000, 1.0000)),
-        "maroon" => Some(Color::srgba(0.5020, 0.0000, 0.0000, 1.0000)),
-        "red" => Some(Color::srgba(1.0000, 0.0000, 0.0000, 1.0000)),
-        "purple" => Some(Color::srgba(0.5020, 0.0000, 0.5020, 1.0000)),
-        "fuchsia" => Some(Color::srgba(1.0000, 0.0000, 1.0000, 1.0000)),
-        "green" => Some(Color::srgba(0.0000, 0.5020, 0.0000, 1.0000)),
-        "lime" => Some(Color::srgba(0.0000, 1.0000, 0.0000, 1.0000)),
-        "olive" => Some(Color::srgba(0.5020, 0.5020, 0.0000, 1.0000)),
-        "yellow" => Some(Color::srgba(1.0000, 1.0000, 0.0000, 1.0000)),
-        "navy" => Some(Color::srgba(0.0000, 0.0000, 0.5020, 1.0000)),
-        "blue" => Some(Color::srgba(0.0000, 0.0000, 1.0000, 1.0000)),
-        "teal" => Some(Color::srgba(0.0000, 0.5020, 0.5020, 1.0000)),
-        "aqua" => Some(Color::srgba(0.0000, 1.0000, 1.0000, 1.0000)),
-
-        // CSS Level 2 values
-        "orange" => Some(Color::srgba(1.0000, 0.6471, 0.0000, 1.0000)),
-
-        // CSS Level 3 values
-        "aliceblue" => Some(Color::srgba(0.9412, 0.9725, 1.0000, 1.0000)),
-        "antiquewhite" => Some(Color::srgba(0.9804, 0.9216, 0.8431, 1.0000)),
-        "aquamarine" => Some(Color::srgba(0.4980, 1.0000, 0.8314, 1.0000)),
-        "azure" => Some(Color::srgba(0.9412, 1.0000, 1.0000, 1.0000)),
-        "beige" => Some(Color::srgba(0.9608, 0.9608, 0.8627, 1.0000)),
-        "bisque" => Some(Color::srgba(1.0000, 0.8941, 0.7686, 1.0000)),
-        "blanchedalmond" => Some(Color::srgba(1.0000, 0.9216, 0.8039, 1.0000)),
-        "blueviolet" => Some(Color::srgba(0.5412, 0.1686, 0.8863, 1.0000)),
-        "brown" => Some(Color::srgba(0.6471, 0.1647, 0.1647, 1.0000)),
-        "burlywood" => Some(Color::srgba(0.8706, 0.7216, 0.5294, 1.0000)),
-        "cadetblue" => Some(Color::srgba(0.3725, 0.6196, 0.6275, 1.0000)),
-        "chartreuse" => Some(Color::srgba(0.4980, 1.0000, 0.0000, 1.0000)),
-        "chocolate" => Some(Color::srgba(0.8235, 0.4118, 0.1176, 1.0000)),
-        "coral" => Some(Color::srgba(1.0000, 0.4980, 0.3137, 1.0000)),
-        "cornflowerblue" => Some(Color::srgba(0.3922, 0.5843, 0.9294, 1.0000)),
-        "cornsilk" => Some(Color::srgba(1.0000, 0.9725, 0.8627, 1.0000)),
-        "crimson" => Some(Color::srgba(0.8627, 0.0784, 0.2353, 1.0000)),
-        "cyan" => Some(Color::srgba(0.0000, 1.0000, 1.0000, 1.0000)),
-        "darkblue" => Some(Color::srgba(0.0000, 0.0000, 0.5451, 1.0000)),
-        "darkcyan" => Some(Color::srgba(0.0000, 0.5451, 0.5451, 1.0000)),
-        "darkgoldenrod" => Some(Color::srgba(0.7216, 0.5255, 0.0431, 1.0000)),
-        "darkgray" => Some(Color::srgba(0.6627, 0.6627, 0.6627, 1.0000)),
-        "darkgreen" => Some(Color::srgba(0.0000, 0.3922, 0.0000, 1.0000)),
-        "darkgrey" => Some(Color::srgba(0.6627, 0.6627, 0.6627, 1.0000)),
-        "darkkhaki" => Some(Color::srgba(0.7412, 0.7176, 0.4196, 1.0000)),
-        "darkmagenta" => Some(Color::srgba(0.5451, 0.0000, 0.5451, 1.0000)),
-        "darkolivegreen" => Some(Color::srgba(0.3333, 0.4196, 0.1843, 1.0000)),
-        "darkorange" => Some(Color::srgba(1.0000, 0.5490, 0.0000, 1.0000)),
-        "darkorchid" => Some(Color::srgba(0.6000, 0.1961, 0.8000, 1.0000)),
-        "darkred" => Some(Color::srgba(0.5451, 0.0000, 0.0000, 1.0000)),
-        "darksalmon" => Some(Color::srgba(0.9137, 0.5882, 0.4784, 1.0000)),
-        "darkseagreen" => Some(Color::srgba(0.5608, 0.7373, 0.5608, 1.0000)),
-        "darkslateblue" => Some(Color::srgba(0.2824, 0.2392, 0.5451, 1.0000)),
-        "darkslategray" => Some(Color::srgba(0.1843, 0.3098, 0.3098, 1.0000)),
-        "darkslategrey" => Some(Color::srgba(0.1843, 0.3098, 0.3098, 1.0000)),
-        "darkturquoise" => Some(Color::srgba(0.0000, 0.8078, 0.8196, 1.0000)),
-        "darkviolet" => Some(Color::srgba(0.5804, 0.0000, 0.8275, 1.0000)),
-        "deeppink" => Some(Color::srgba(1.0000, 0.0784, 0.5765, 1.0000)),
-        "deepskyblue" => Some(Color::srgba(0.0000, 0.7490, 1.0000, 1.0000)),
-        "dimgray" => Some(Color::srgba(0.4118, 0.4118, 0.4118, 1.0000)),
-        "dimgrey" => Some(Color::srgba(0.4118, 0.4118, 0.4118, 1.0000)),
-        "dodgerblue" => Some(Color::srgba(0.1176, 0.5647, 1.0000, 1.0000)),
-        "firebrick" => Some(Color::srgba(0.6980, 0.1333, 0.1333, 1.0000)),
-        "floralwhite" => Some(Color::srgba(1.0000, 0.9804, 0.9412, 1.0000)),
-        "forestgreen" => Some(Color::srgba(0.1333, 0.5451, 0.1333, 1.0000)),
-        "gainsboro" => Some(Color::srgba(0.8627, 0.8627, 0.8627, 1.0000)),
-        "ghostwhite" => Some(Color::srgba(0.9725, 0.9725, 1.0000, 1.0000)),
-        "gold" => Some(Color::srgba(1.0000, 0.8431, 0.0000, 1.0000)),
-        "goldenrod" => Some(Color::srgba(0.8549, 0.6471, 0.1255, 1.0000)),
-        "greenyellow" => Some(Color::srgba(0.6784, 1.0000, 0.1843, 1.0000)),
-        "grey" => Some(Color::srgba(0.5020, 0.5020, 0.5020, 1.0000)),
-        "honeydew" => Some(Color::srgba(0.9412, 1.0000, 0.9412, 1.0000)),
-        "hotpink" => Some(Color::srgba(1.0000, 0.4118, 0.7059, 1.0000)),
-        "indianred" => Some(Color::srgba(0.8039, 0.3608, 0.3608, 1.0000)),
-        "indigo" => Some(Color::srgba(0.2941, 0.0000, 0.5098, 1.0000)),
-        "ivory" => Some(Color::srgba(1.0000, 1.0000, 0.9412, 1.0000)),
-        "khaki" => Some(Color::srgba(0.9412, 0.9020, 0.5490, 1.0000)),
-        "lavender" => Some(Color::srgba(0.9020, 0.9020, 0.9804, 1.0000)),
-        "lavenderblush" => Some(Color::srgba(1.0000, 0.9412, 0.9608, 1.0000)),
-        "lawngreen" => Some(Color::srgba(0.4863, 0.9882, 0.0000, 1.0000)),
-        "lemonchiffon" => Some(Color::srgba(1.0000, 0.9804, 0.8039, 1.0000)),
-        "lightblue" => Some(Color::srgba(0.6784, 0.8471, 0.9020, 1.0000)),
-        "lightcoral" => Some(Color::srgba(0.9412, 0.5020, 0.5020, 1.0000)),
-        "lightcyan" => Some(Color::srgba(0.8784, 1.0000, 1.0000, 1.0000)),
-        "lightgoldenrodyellow" => Some(Color::srgba(0.9804, 0.9804, 0.8235, 1.0000)),
-        "lightgray" => Some(Color::srgba(0.8275, 0.8275, 0.8275, 1.0000)),
-        "lightgreen" => Some(Color::srgba(0.5647, 0.9333, 0.5647, 1.0000)),
-        "lightgrey" => Some(Color::srgba(0.8275, 0.8275, 0.8275, 1.0000)),
-        "lightpink" => Some(Color::srgba(1.0000, 0.7137, 0.7569, 1.0000)),
-        "lightsalmon" => Some(Color::srgba(1.0000, 0.6275, 0.4784, 1.0000)),
-        "lightseagreen" => Some(Color::srgba(0.1255, 0.6980, 0.6667, 1.0000)),
-        "lightskyblue" => Some(Color::srgba(0.5294, 0.8078, 0.9804, 1.0000)),
-        "lightslategray" => Some(Color::srgba(0.4667, 0.5333, 0.6000, 1.0000)),
-        "lightslategrey" => Some(Color::srgba(0.4667, 0.5333, 0.6000, 1.0000)),
-        "lightsteelblue" => Some(Color::srgba(0.6902, 0.7686, 0.8706, 1.0000)),
-        "lightyellow" => Some(Color::srgba(1.0000, 1.0000, 0.8784, 1.0000)),
-        "limegreen" => Some(Color::srgba(0.1961, 0.8039, 0.1961, 1.0000)),
-        "linen" => Some(Color::srgba(0.9804, 0.9412, 0.9020, 1.0000)),
-        "magenta" => Some(Color::srgba(1.0000, 0.0000, 1.0000, 1.0000)),
-        "mediumaquamarine" => Some(Color::srgba(0.4000, 0.8039, 0.6667, 1.0000)),
-        "mediumblue" => Some(Color::srgba(0.0000, 0.0000, 0.8039, 1.0000)),
-        "mediumorchid" => Some(Color::srgba(0.7294, 0.3333, 0.8275, 1.0000)),
-        "mediumpurple" => Some(Color::srgba(0.5765, 0.4392, 0.8588, 1.0000)),
-        "mediumseagreen" => Some(Color::srgba(0.2353, 0.7020, 0.4431, 1.0000)),
-        "mediumslateblue" => Some(Color::srgba(0.4824, 0.4078, 0.9333, 1.0000)),
-        "mediumspringgreen" => Some(Color::srgba(0.0000, 0.9804, 0.6039, 1.0000)),
-        "mediumturquoise" => Some(Color::srgba(0.2824, 0.8196, 0.8000, 1.0000)),
-        "mediumvioletred" => Some(Color::srgba(0.7804, 0.0824, 0.5216, 1.0000)),
-        "midnightblue" => Some(Color::srgba(0.0980, 0.0980, 0.4392, 1.0000)),
-        "mintcream" => Some(Color::srgba(0.9608, 1.0000, 0.9804, 1.0000)),
-        "mistyrose" => Some(Color::srgba(1.0000, 0.8941, 0.8824, 1.0000)),
-        "moccasin" => Some(Color::srgba(1.0000, 0.8941, 0.7098, 1.0000)),
-        "navajowhite" => Some(Color::srgba(1.0000, 0.8706, 0.6784, 1.0000)),
-        "oldlace" => Some(Color::srgba(0.9922, 0.9608, 0.9020, 1.0000)),
-        "olivedrab" => Some(Color::srgba(0.4196, 0.5569, 0.1373, 1.0000)),
-        "orangered" => Some(Color::srgba(1.0000, 0.2706, 0.0000, 1.0000)),
-        "orchid" => Some(Color::srgba(0.8549, 0.4392, 0.8392, 1.0000)),
-        "palegoldenrod" => Some(Color::srgba(0.9333, 0.9098, 0.6667, 1.0000)),
-        "palegreen" => Some(Color::srgba(0.5961, 0.9843, 0.5961, 1.0000)),
-        "paleturquoise" => Some(Color::srgba(0.6863, 0.9333, 0.9333, 1.0000)),
-        "palevioletred" => Some(Color::srgba(0.8588, 0.4392, 0.5765, 1.0000)),
-        "papayawhip" => Some(Color::srgba(1.0000, 0.9373, 0.8353, 1.0000)),
-        "peachpuff" => Some(Color::srgba(1.0000, 0.8549, 0.7255, 1.0000)),
-        "peru" => Some(Color::srgba(0.8039, 0.5216, 0.2471, 1.0000)),
-        "pink" => Some(Color::srgba(1.0000, 0.7529, 0.7961, 1.0000)),
-        "plum" => Some(Color::srgba(0.8667, 0.6275, 0.8667, 1.0000)),
-        "powderblue" => Some(Color::srgba(0.6902, 0.8784, 0.9020, 1.0000)),
-        "rosybrown" => Some(Color::srgba(0.7373, 0.5608, 0.5608, 1.0000)),
-        "royalblue" => Some(Color::srgba(0.2549, 0.4118, 0.8824, 1.0000)),
-        "saddlebrown" => Some(Color::srgba(0.5451, 0.2706, 0.0745, 1.0000)),
-        "salmon" => Some(Color::srgba(0.9804, 0.5020, 0.4471, 1.0000)),
-        "sandybrown" => Some(Color::srgba(0.9569, 0.6431, 0.3765, 1.0000)),
-        "seagreen" => Some(Color::srgba(0.1804, 0.5451, 0.3412, 1.0000)),
-        "seashell" => Some(Color::srgba(1.0000, 0.9608, 0.9333, 1.0000)),
-        "sienna" => Some(Color::srgba(0.6275, 0.3216, 0.1765, 1.0000)),
-        "skyblue" => Some(Color::srgba(0.5294, 0.8078, 0.9216, 1.0000)),
-        "slateblue" => Some(Color::srgba(0.4157, 0.3529, 0.8039, 1.0000)),
-        "slategray" => Some(Color::srgba(0.4392, 0.5020, 0.5647, 1.0000)),
-        "slategrey" => Some(Color::srgba(0.4392, 0.5020, 0.5647, 1.0000)),
-        "snow" => Some(Color::srgba(1.0000, 0.9804, 0.9804, 1.0000)),
-        "springgreen" => Some(Color::srgba(0.0000, 1.0000, 0.4980, 1.0000)),
-        "steelblue" => Some(Color::srgba(0.2745, 0.5098, 0.7059, 1.0000)),
-        "tan" => Some(Color::srgba(0.8235, 0.7059, 0.5490, 1.0000)),
-        "thistle" => Some(Color::srgba(0.8471, 0.7490, 0.8471, 1.0000)),
-        "tomato" => Some(Color::srgba(1.0000, 0.3882, 0.2784, 1.0000)),
-        "transparent" => Some(Color::srgba(0.0000, 0.0000, 0.0000, 0.0000)),
-        "turquoise" => Some(Color::srgba(0.2510, 0.8784, 0.8157, 1.0000)),
-        "violet" => Some(Color::srgba(0.9333, 0.5098, 0.9333, 1.0000)),
-        "wheat" => Some(Color::srgba(0.9608, 0.8706, 0.7020, 1.0000)),
-        "whitesmoke" => Some(Color::srgba(0.9608, 0.9608, 0.9608, 1.0000)),
-        "yellowgreen" => Some(Color::srgba(0.6039, 0.8039, 0.1961, 1.0000)),
-
-        // CSS Level 4 values
-        "rebeccapurple" => Some(Color::srgba(0.4000, 0.2000, 0.6000, 1.0000)),
-        _ => None,
+/// The lookup table itself is generated from `colors.txt` at build time
+/// by `build.rs`, so adding a keyword is a one-line data edit.
+include!(concat!(env!("OUT_DIR"), "/named_colors.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rgb_with_commas() {
+        assert_eq!(
+            parse_color("rgb(255, 99, 71)").unwrap(),
+            Color::srgba_u8(255, 99, 71, 255)
+        );
+    }
+
+    #[test]
+    fn parses_rgba_with_percentage_alpha() {
+        assert_eq!(
+            parse_color("rgba(255, 99, 71, 50%)").unwrap(),
+            Color::srgba_u8(255, 99, 71, 128)
+        );
+    }
+
+    #[test]
+    fn parses_rgb_with_space_and_slash_syntax() {
+        assert_eq!(
+            parse_color("rgb(255 0 0 / 50%)").unwrap(),
+            Color::srgba_u8(255, 0, 0, 128)
+        );
+    }
+
+    #[test]
+    fn parses_rgb_percentage_channels() {
+        assert_eq!(
+            parse_color("rgb(50%, 50%, 50%)").unwrap(),
+            Color::srgba_u8(128, 128, 128, 255)
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_range_channel() {
+        assert!(parse_color("rgb(256, 0, 0)").is_err());
+    }
+
+    #[test]
+    fn rejects_wrong_channel_count() {
+        assert!(parse_color("rgb(255, 0)").is_err());
+    }
+
+    #[test]
+    fn parses_hsl() {
+        let color = parse_color("hsl(120, 50%, 40%)").unwrap().to_srgba();
+        assert!((color.red - 0.2).abs() < 0.01);
+        assert!((color.green - 0.6).abs() < 0.01);
+        assert!((color.blue - 0.2).abs() < 0.01);
+    }
+
+    #[test]
+    fn parses_hsla() {
+        let color = parse_color("hsla(0, 100%, 50%, 0.5)").unwrap();
+        assert_eq!(color.to_srgba().alpha, 0.5);
+    }
+
+    #[test]
+    fn falls_back_to_hex_and_named() {
+        assert_eq!(parse_color("#ff0000").unwrap(), Color::srgba_u8(255, 0, 0, 255));
+        assert_eq!(parse_color("red").unwrap(), Color::srgba_u8(255, 0, 0, 255));
+    }
+
+    #[test]
+    fn parse_color_for_entity_resolves_role_from_ancestor_palette() {
+        use bevy::hierarchy::BuildWorldChildren;
+        use bevy::prelude::World;
+
+        let mut world = World::new();
+        let mut root_palette = Palette::default();
+        root_palette.set(super::super::palette::ColorRole::Accent, Color::srgba(1.0, 0.0, 0.0, 1.0));
+        let root = world.spawn(root_palette).id();
+        let leaf = world.spawn_empty().id();
+        world.entity_mut(root).add_child(leaf);
+
+        let default_palette = DefaultPalette::default();
+        let mut palette_state = world.query::<&Palette>();
+        let mut parent_state = world.query::<&Parent>();
+        let palettes = palette_state.query(&world);
+        let parents = parent_state.query(&world);
+
+        let color =
+            parse_color_for_entity("role(accent)", leaf, &palettes, &parents, &default_palette)
+                .unwrap();
+        assert_eq!(color, Color::srgba(1.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn get_hex_does_not_overflow_at_full_intensity() {
+        assert_eq!(Color::WHITE.get_hex(), "#ffffff");
+        assert_eq!(Color::srgba(1.0, 1.0, 1.0, 1.0).get_hex(), "#ffffff");
+    }
+
+    #[test]
+    fn mix_interpolates_between_two_colors() {
+        let a = Color::srgba(0.0, 0.0, 0.0, 1.0);
+        let b = Color::srgba(1.0, 1.0, 1.0, 1.0);
+        assert_eq!(a.mix(b, 0.0), a);
+        assert_eq!(a.mix(b, 1.0), b);
+        let mid = a.mix(b, 0.5).to_srgba();
+        assert!((mid.red - 0.5).abs() < 0.001);
+        assert!((mid.green - 0.5).abs() < 0.001);
+        assert!((mid.blue - 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn get_hsl_then_set_hsl_round_trips_primaries() {
+        for primary in [
+            Color::srgba(1.0, 0.0, 0.0, 1.0),
+            Color::srgba(0.0, 1.0, 0.0, 1.0),
+            Color::srgba(0.0, 0.0, 1.0, 1.0),
+        ] {
+            let (h, s, l, a) = primary.get_hsl();
+            let mut roundtripped = Color::WHITE;
+            roundtripped.set_hsl(h, s, l);
+            roundtripped = roundtripped.with_alpha(a);
+            let expected = primary.to_srgba();
+            let actual = roundtripped.to_srgba();
+            assert!((expected.red - actual.red).abs() < 0.01);
+            assert!((expected.green - actual.green).abs() < 0.01);
+            assert!((expected.blue - actual.blue).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn lighten_and_darken_clamp_at_bounds() {
+        let white = Color::srgba(1.0, 1.0, 1.0, 1.0);
+        let black = Color::srgba(0.0, 0.0, 0.0, 1.0);
+        assert_eq!(white.lighten(0.5).get_hsl().2, 1.0);
+        assert_eq!(black.darken(0.5).get_hsl().2, 0.0);
+    }
+
+    #[test]
+    fn lighten_raises_lightness() {
+        let base = Color::srgba(0.5, 0.0, 0.0, 1.0);
+        let (_, _, l0, _) = base.get_hsl();
+        let (_, _, l1, _) = base.lighten(0.2).get_hsl();
+        assert!(l1 > l0);
+    }
+
+    #[test]
+    fn darken_lowers_lightness() {
+        let base = Color::srgba(0.5, 0.0, 0.0, 1.0);
+        let (_, _, l0, _) = base.get_hsl();
+        let (_, _, l1, _) = base.darken(0.2).get_hsl();
+        assert!(l1 < l0);
+    }
+
+    #[test]
+    fn with_alpha_replaces_only_alpha_channel() {
+        let base = Color::srgba(0.2, 0.4, 0.6, 1.0);
+        let replaced = base.with_alpha(0.5).to_srgba();
+        assert_eq!(replaced.red, 0.2);
+        assert_eq!(replaced.green, 0.4);
+        assert_eq!(replaced.blue, 0.6);
+        assert_eq!(replaced.alpha, 0.5);
     }
 }
@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use bevy::prelude::Color;
+
+use crate::ElementsError;
+
+/// A curated, coherent set of colors shipped with belly so users can pick a
+/// ready-made scheme instead of hand-picking hex values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Flavor {
+    Latte,
+    Frappe,
+    Macchiato,
+    Mocha,
+}
+
+impl Flavor {
+    pub const ALL: [Flavor; 4] = [Flavor::Latte, Flavor::Frappe, Flavor::Macchiato, Flavor::Mocha];
+
+    pub fn iter() -> impl Iterator<Item = Flavor> {
+        Flavor::ALL.into_iter()
+    }
+
+    fn parse(name: &str) -> Option<Flavor> {
+        match name.trim().to_lowercase().as_str() {
+            "latte" => Some(Flavor::Latte),
+            "frappe" => Some(Flavor::Frappe),
+            "macchiato" => Some(Flavor::Macchiato),
+            "mocha" => Some(Flavor::Mocha),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`PaletteSet`] of named swatches for this flavor,
+    /// building it once on first access.
+    pub fn palette(&self) -> &'static PaletteSet {
+        static LATTE: OnceLock<PaletteSet> = OnceLock::new();
+        static FRAPPE: OnceLock<PaletteSet> = OnceLock::new();
+        static MACCHIATO: OnceLock<PaletteSet> = OnceLock::new();
+        static MOCHA: OnceLock<PaletteSet> = OnceLock::new();
+
+        match self {
+            Flavor::Latte => LATTE.get_or_init(|| PaletteSet::from_hex(LATTE_SWATCHES)),
+            Flavor::Frappe => FRAPPE.get_or_init(|| PaletteSet::from_hex(FRAPPE_SWATCHES)),
+            Flavor::Macchiato => MACCHIATO.get_or_init(|| PaletteSet::from_hex(MACCHIATO_SWATCHES)),
+            Flavor::Mocha => MOCHA.get_or_init(|| PaletteSet::from_hex(MOCHA_SWATCHES)),
+        }
+    }
+}
+
+/// A named set of swatches belonging to a single [`Flavor`].
+#[derive(Debug, Clone)]
+pub struct PaletteSet {
+    swatches: HashMap<&'static str, Color>,
+}
+
+impl PaletteSet {
+    fn from_hex(entries: &[(&'static str, u32)]) -> PaletteSet {
+        let swatches = entries
+            .iter()
+            .map(|(name, hex)| {
+                let r = ((hex >> 16) & 0xff) as u8;
+                let g = ((hex >> 8) & 0xff) as u8;
+                let b = (hex & 0xff) as u8;
+                (*name, Color::srgba_u8(r, g, b, 0xff))
+            })
+            .collect();
+        PaletteSet { swatches }
+    }
+
+    pub fn swatch(&self, name: &str) -> Option<Color> {
+        self.swatches.get(name).copied()
+    }
+}
+
+// Canonical Catppuccin swatch names and hex values for each flavor.
+// https://github.com/catppuccin/catppuccin
+
+const LATTE_SWATCHES: &[(&str, u32)] = &[
+    ("rosewater", 0xdc8a78),
+    ("flamingo", 0xdd7878),
+    ("pink", 0xea76cb),
+    ("mauve", 0x8839ef),
+    ("red", 0xd20f39),
+    ("maroon", 0xe64553),
+    ("peach", 0xfe640b),
+    ("yellow", 0xdf8e1d),
+    ("green", 0x40a02b),
+    ("teal", 0x179299),
+    ("sky", 0x04a5e5),
+    ("sapphire", 0x209fb5),
+    ("blue", 0x1e66f5),
+    ("lavender", 0x7287fd),
+    ("text", 0x4c4f69),
+    ("subtext1", 0x5c5f77),
+    ("subtext0", 0x6c6f85),
+    ("overlay2", 0x7c7f93),
+    ("overlay1", 0x8c8fa1),
+    ("overlay0", 0x9ca0b0),
+    ("surface2", 0xacb0be),
+    ("surface1", 0xbcc0cc),
+    ("surface0", 0xccd0da),
+    ("base", 0xeff1f5),
+    ("mantle", 0xe6e9ef),
+    ("crust", 0xdce0e8),
+];
+
+const FRAPPE_SWATCHES: &[(&str, u32)] = &[
+    ("rosewater", 0xf2d5cf),
+    ("flamingo", 0xeebebe),
+    ("pink", 0xf4b8e4),
+    ("mauve", 0xca9ee6),
+    ("red", 0xe78284),
+    ("maroon", 0xea999c),
+    ("peach", 0xef9f76),
+    ("yellow", 0xe5c890),
+    ("green", 0xa6d189),
+    ("teal", 0x81c8be),
+    ("sky", 0x99d1db),
+    ("sapphire", 0x85c1dc),
+    ("blue", 0x8caaee),
+    ("lavender", 0xbabbf1),
+    ("text", 0xc6d0f5),
+    ("subtext1", 0xb5bfe2),
+    ("subtext0", 0xa5adce),
+    ("overlay2", 0x949cbb),
+    ("overlay1", 0x838ba7),
+    ("overlay0", 0x737994),
+    ("surface2", 0x626880),
+    ("surface1", 0x51576d),
+    ("surface0", 0x414559),
+    ("base", 0x303446),
+    ("mantle", 0x292c3c),
+    ("crust", 0x232634),
+];
+
+const MACCHIATO_SWATCHES: &[(&str, u32)] = &[
+    ("rosewater", 0xf4dbd6),
+    ("flamingo", 0xf0c6c6),
+    ("pink", 0xf5bde6),
+    ("mauve", 0xc6a0f6),
+    ("red", 0xed8796),
+    ("maroon", 0xee99a0),
+    ("peach", 0xf5a97f),
+    ("yellow", 0xeed49f),
+    ("green", 0xa6da95),
+    ("teal", 0x8bd5ca),
+    ("sky", 0x91d7e3),
+    ("sapphire", 0x7dc4e4),
+    ("blue", 0x8aadf4),
+    ("lavender", 0xb7bdf8),
+    ("text", 0xcad3f5),
+    ("subtext1", 0xb8c0e0),
+    ("subtext0", 0xa5adcb),
+    ("overlay2", 0x939ab7),
+    ("overlay1", 0x8087a2),
+    ("overlay0", 0x6e738d),
+    ("surface2", 0x5b6078),
+    ("surface1", 0x494d64),
+    ("surface0", 0x363a4f),
+    ("base", 0x24273a),
+    ("mantle", 0x1e2030),
+    ("crust", 0x181926),
+];
+
+const MOCHA_SWATCHES: &[(&str, u32)] = &[
+    ("rosewater", 0xf5e0dc),
+    ("flamingo", 0xf2cdcd),
+    ("pink", 0xf5c2e7),
+    ("mauve", 0xcba6f7),
+    ("red", 0xf38ba8),
+    ("maroon", 0xeba0ac),
+    ("peach", 0xfab387),
+    ("yellow", 0xf9e2af),
+    ("green", 0xa6e3a1),
+    ("teal", 0x94e2d5),
+    ("sky", 0x89dceb),
+    ("sapphire", 0x74c7ec),
+    ("blue", 0x89b4fa),
+    ("lavender", 0xb4befe),
+    ("text", 0xcdd6f4),
+    ("subtext1", 0xbac2de),
+    ("subtext0", 0xa6adc8),
+    ("overlay2", 0x9399b2),
+    ("overlay1", 0x7f849c),
+    ("overlay0", 0x6c7086),
+    ("surface2", 0x585b70),
+    ("surface1", 0x45475a),
+    ("surface0", 0x313244),
+    ("base", 0x1e1e2e),
+    ("mantle", 0x181825),
+    ("crust", 0x11111b),
+];
+
+/// Parses a `flavor(<flavor>, <swatch>)` stylesheet token, e.g.
+/// `flavor(mocha, teal)`.
+pub(super) fn parse_flavor_color(args: &str) -> Result<Color, ElementsError> {
+    let err = || ElementsError::InvalidPropertyValue(format!("Can't parse color from 'flavor({args})'"));
+    let mut parts = args.split(',').map(|p| p.trim());
+    let flavor_name = parts.next().ok_or_else(err)?;
+    let swatch_name = parts.next().ok_or_else(err)?;
+    if parts.next().is_some() {
+        return Err(err());
+    }
+    let flavor = Flavor::parse(flavor_name).ok_or_else(err)?;
+    flavor.palette().swatch(swatch_name).ok_or_else(err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_flavor_swatch_token() {
+        assert_eq!(
+            parse_flavor_color("mocha, teal").unwrap(),
+            Color::srgba_u8(0x94, 0xe2, 0xd5, 0xff)
+        );
+    }
+
+    #[test]
+    fn flavor_parse_is_case_insensitive_and_trims() {
+        assert_eq!(Flavor::parse(" Mocha "), Some(Flavor::Mocha));
+        assert_eq!(Flavor::parse("latte"), Some(Flavor::Latte));
+        assert_eq!(Flavor::parse("bogus"), None);
+    }
+
+    #[test]
+    fn rejects_unknown_flavor() {
+        assert!(parse_flavor_color("nonexistent, teal").is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_swatch() {
+        assert!(parse_flavor_color("mocha, nonexistent").is_err());
+    }
+
+    #[test]
+    fn rejects_wrong_arg_count() {
+        assert!(parse_flavor_color("mocha").is_err());
+        assert!(parse_flavor_color("mocha, teal, extra").is_err());
+    }
+
+    #[test]
+    fn palette_set_swatch_looks_up_by_name() {
+        let palette = Flavor::Latte.palette();
+        assert_eq!(palette.swatch("base"), Some(Color::srgba_u8(0xef, 0xf1, 0xf5, 0xff)));
+        assert_eq!(palette.swatch("nonexistent"), None);
+    }
+
+    #[test]
+    fn iter_covers_all_flavors() {
+        let flavors: Vec<Flavor> = Flavor::iter().collect();
+        assert_eq!(flavors, Flavor::ALL.to_vec());
+    }
+}
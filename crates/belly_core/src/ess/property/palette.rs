@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+use bevy::prelude::{Color, Component, Entity, Parent, Query, Resource};
+
+use crate::ElementsError;
+
+/// A semantic slot a widget can reference instead of a raw [`Color`], so
+/// swapping the active [`Palette`] recolors the whole UI in one go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorRole {
+    Background,
+    Foreground,
+    Accent,
+    Selection,
+    Border,
+    Disabled,
+}
+
+impl ColorRole {
+    fn parse(name: &str) -> Option<ColorRole> {
+        match name.trim().to_lowercase().as_str() {
+            "background" => Some(ColorRole::Background),
+            "foreground" => Some(ColorRole::Foreground),
+            "accent" => Some(ColorRole::Accent),
+            "selection" => Some(ColorRole::Selection),
+            "border" => Some(ColorRole::Border),
+            "disabled" => Some(ColorRole::Disabled),
+            _ => None,
+        }
+    }
+}
+
+/// A set of colors keyed by [`ColorRole`]. Widgets reference a role rather
+/// than a raw color, so a single `Palette` swap recolors everything that
+/// points at it.
+///
+/// `Palette` is a [`Component`]: attach it to a root element for the whole
+/// subtree, or to any descendant to override just that branch. Lookups walk
+/// up the entity hierarchy through [`Parent`] until a `Palette` is found,
+/// falling back to the app-wide [`DefaultPalette`] resource.
+#[derive(Component, Debug, Clone)]
+pub struct Palette {
+    roles: HashMap<ColorRole, Color>,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        let mut roles = HashMap::new();
+        roles.insert(ColorRole::Background, Color::srgba(0.1, 0.1, 0.1, 1.0));
+        roles.insert(ColorRole::Foreground, Color::srgba(0.9, 0.9, 0.9, 1.0));
+        roles.insert(ColorRole::Accent, Color::srgba(0.2, 0.5, 1.0, 1.0));
+        roles.insert(ColorRole::Selection, Color::srgba(0.2, 0.5, 1.0, 0.35));
+        roles.insert(ColorRole::Border, Color::srgba(0.3, 0.3, 0.3, 1.0));
+        roles.insert(ColorRole::Disabled, Color::srgba(0.4, 0.4, 0.4, 0.5));
+        Palette { roles }
+    }
+}
+
+impl Palette {
+    pub fn get(&self, role: ColorRole) -> Color {
+        self.roles
+            .get(&role)
+            .copied()
+            .unwrap_or(Color::srgba(1.0, 0.0, 1.0, 1.0))
+    }
+
+    pub fn set(&mut self, role: ColorRole, color: Color) {
+        self.roles.insert(role, color);
+    }
+}
+
+/// The app-wide fallback [`Palette`] used when an entity has no `Palette`
+/// component anywhere in its ancestor chain.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct DefaultPalette(pub Palette);
+
+/// Resolves `role` for `entity` by walking up through [`Parent`] looking
+/// for the nearest ancestor (inclusive) carrying a [`Palette`] component,
+/// falling back to `default_palette` when none is found.
+pub fn resolve_role(
+    role: ColorRole,
+    entity: Entity,
+    palettes: &Query<&Palette>,
+    parents: &Query<&Parent>,
+    default_palette: &DefaultPalette,
+) -> Color {
+    let mut current = entity;
+    loop {
+        if let Ok(palette) = palettes.get(current) {
+            return palette.get(role);
+        }
+        match parents.get(current) {
+            Ok(parent) => current = parent.get(),
+            Err(_) => return default_palette.0.get(role),
+        }
+    }
+}
+
+/// Process-wide fallback `Palette`, shared across the task pool via a
+/// [`RwLock`] so worker threads observe updates made with
+/// [`set_fallback_palette`] instead of each seeing their own copy.
+static FALLBACK_PALETTE: OnceLock<RwLock<Palette>> = OnceLock::new();
+
+fn fallback_palette() -> &'static RwLock<Palette> {
+    FALLBACK_PALETTE.get_or_init(|| RwLock::new(Palette::default()))
+}
+
+/// Replaces the process-wide fallback [`Palette`] used to resolve
+/// `role(...)` tokens when parsing happens outside of an ECS query (e.g. a
+/// one-off string parse with no entity context). Systems that do have
+/// entity context should prefer [`resolve_role`] for correct
+/// parent/child scoping.
+pub fn set_fallback_palette(palette: Palette) {
+    *fallback_palette().write().unwrap() = palette;
+}
+
+/// Parses a `role(<name>)` stylesheet token against the process-wide
+/// fallback [`Palette`]. Used when no entity context is available; see
+/// [`parse_role_color_for_entity`] for hierarchy-aware lookup.
+pub(super) fn parse_role_color(name: &str) -> Result<Color, ElementsError> {
+    let role = ColorRole::parse(name).ok_or_else(|| {
+        ElementsError::InvalidPropertyValue(format!("Unknown color role '{name}'"))
+    })?;
+    Ok(fallback_palette().read().unwrap().get(role))
+}
+
+/// Parses a `role(<name>)` stylesheet token against `entity`'s effective
+/// [`Palette`], resolved by walking its ancestor chain via [`resolve_role`].
+pub(super) fn parse_role_color_for_entity(
+    name: &str,
+    entity: Entity,
+    palettes: &Query<&Palette>,
+    parents: &Query<&Parent>,
+    default_palette: &DefaultPalette,
+) -> Result<Color, ElementsError> {
+    let role = ColorRole::parse(name).ok_or_else(|| {
+        ElementsError::InvalidPropertyValue(format!("Unknown color role '{name}'"))
+    })?;
+    Ok(resolve_role(role, entity, palettes, parents, default_palette))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::hierarchy::BuildWorldChildren;
+    use bevy::prelude::World;
+
+    #[test]
+    fn resolve_role_walks_up_to_nearest_ancestor_palette() {
+        let mut world = World::new();
+
+        let mut root_palette = Palette::default();
+        root_palette.set(ColorRole::Accent, Color::srgba(1.0, 0.0, 0.0, 1.0));
+        let root = world.spawn(root_palette).id();
+
+        let mid = world.spawn_empty().id();
+        let leaf = world.spawn_empty().id();
+        world.entity_mut(root).add_child(mid);
+        world.entity_mut(mid).add_child(leaf);
+
+        let default_palette = DefaultPalette::default();
+        let mut palette_state = world.query::<&Palette>();
+        let mut parent_state = world.query::<&Parent>();
+        let palettes = palette_state.query(&world);
+        let parents = parent_state.query(&world);
+
+        let color = resolve_role(ColorRole::Accent, leaf, &palettes, &parents, &default_palette);
+        assert_eq!(color, Color::srgba(1.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn resolve_role_overrides_at_nearer_ancestor() {
+        let mut world = World::new();
+
+        let mut root_palette = Palette::default();
+        root_palette.set(ColorRole::Accent, Color::srgba(1.0, 0.0, 0.0, 1.0));
+        let root = world.spawn(root_palette).id();
+
+        let mut mid_palette = Palette::default();
+        mid_palette.set(ColorRole::Accent, Color::srgba(0.0, 1.0, 0.0, 1.0));
+        let mid = world.spawn(mid_palette).id();
+        let leaf = world.spawn_empty().id();
+        world.entity_mut(root).add_child(mid);
+        world.entity_mut(mid).add_child(leaf);
+
+        let default_palette = DefaultPalette::default();
+        let mut palette_state = world.query::<&Palette>();
+        let mut parent_state = world.query::<&Parent>();
+        let palettes = palette_state.query(&world);
+        let parents = parent_state.query(&world);
+
+        let color = resolve_role(ColorRole::Accent, leaf, &palettes, &parents, &default_palette);
+        assert_eq!(color, Color::srgba(0.0, 1.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn resolve_role_falls_back_to_default_palette() {
+        let mut world = World::new();
+        let leaf = world.spawn_empty().id();
+
+        let mut default_palette = DefaultPalette::default();
+        default_palette.0.set(ColorRole::Border, Color::srgba(0.5, 0.5, 0.5, 1.0));
+        let mut palette_state = world.query::<&Palette>();
+        let mut parent_state = world.query::<&Parent>();
+        let palettes = palette_state.query(&world);
+        let parents = parent_state.query(&world);
+
+        let color = resolve_role(ColorRole::Border, leaf, &palettes, &parents, &default_palette);
+        assert_eq!(color, Color::srgba(0.5, 0.5, 0.5, 1.0));
+    }
+}
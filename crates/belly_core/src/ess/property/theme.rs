@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+use bevy::prelude::{Color, Resource};
+
+use crate::ElementsError;
+
+/// A single theme entry: either a concrete [`Color`] or a link to another
+/// key in the same [`ThemeColors`] map, resolved transitively at lookup
+/// time. Links let authors define a color once and have many keys re-point
+/// at it.
+#[derive(Debug, Clone)]
+pub enum ThemeValue {
+    Color(Color),
+    Link(String),
+}
+
+/// A map of theme keys to [`ThemeValue`]s, resolved through `var(...)`
+/// tokens in stylesheets. Insert this as an ECS [`Resource`] (e.g.
+/// `app.insert_resource(theme)`) so systems can read and mutate the active
+/// theme with `Res`/`ResMut` like any other resource.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ThemeColors {
+    entries: HashMap<String, ThemeValue>,
+}
+
+impl ThemeColors {
+    pub fn set_color(&mut self, key: impl Into<String>, color: Color) {
+        self.entries.insert(key.into(), ThemeValue::Color(color));
+    }
+
+    pub fn set_link(&mut self, key: impl Into<String>, target: impl Into<String>) {
+        self.entries.insert(key.into(), ThemeValue::Link(target.into()));
+    }
+
+    pub fn get(&self, key: &str) -> Option<&ThemeValue> {
+        self.entries.get(key)
+    }
+}
+
+/// Follows `Link` chains starting at `key` until a concrete [`Color`] is
+/// reached, detecting cycles along the way.
+pub fn resolve(theme: &ThemeColors, key: &str) -> Result<Color, ElementsError> {
+    let mut visited = vec![key.to_string()];
+    let mut current = key;
+    loop {
+        match theme.get(current) {
+            Some(ThemeValue::Color(color)) => return Ok(*color),
+            Some(ThemeValue::Link(next)) => {
+                if visited.contains(next) {
+                    return Err(ElementsError::InvalidPropertyValue(format!(
+                        "Cyclic theme color link detected at '{next}'"
+                    )));
+                }
+                visited.push(next.clone());
+                current = next;
+            }
+            None => {
+                return Err(ElementsError::InvalidPropertyValue(format!(
+                    "Unknown theme color key '{current}'"
+                )))
+            }
+        }
+    }
+}
+
+/// Process-wide fallback `ThemeColors`, shared across the task pool via a
+/// [`RwLock`] so stylesheet parsing on a worker thread observes the same
+/// theme a `thread_local!` could silently miss, instead of each thread
+/// falling back to its own `Default::default()`.
+static FALLBACK_THEME: OnceLock<RwLock<ThemeColors>> = OnceLock::new();
+
+fn fallback_theme() -> &'static RwLock<ThemeColors> {
+    FALLBACK_THEME.get_or_init(|| RwLock::new(ThemeColors::default()))
+}
+
+/// Replaces the process-wide fallback [`ThemeColors`] used to resolve
+/// `var(...)` tokens when parsing happens outside of a system with access
+/// to the `ThemeColors` resource (e.g. a one-off string parse). Systems
+/// that hold `Res<ThemeColors>` should call [`resolve`] directly against
+/// it instead, so edits made through `ResMut` take effect immediately.
+pub fn set_fallback_theme(theme: ThemeColors) {
+    *fallback_theme().write().unwrap() = theme;
+}
+
+/// Parses a `var(<key>)` stylesheet token against the process-wide
+/// fallback [`ThemeColors`]. See [`resolve`] for resolving against a
+/// specific `ThemeColors` resource.
+pub(super) fn parse_var_color(key: &str) -> Result<Color, ElementsError> {
+    resolve(&fallback_theme().read().unwrap(), key.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_direct_color() {
+        let mut theme = ThemeColors::default();
+        theme.set_color("primary", Color::srgba(1.0, 0.0, 0.0, 1.0));
+        assert_eq!(resolve(&theme, "primary").unwrap(), Color::srgba(1.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn resolves_transitive_link_chain() {
+        let mut theme = ThemeColors::default();
+        theme.set_color("base", Color::srgba(0.0, 1.0, 0.0, 1.0));
+        theme.set_link("accent", "brand");
+        theme.set_link("brand", "base");
+        assert_eq!(resolve(&theme, "accent").unwrap(), Color::srgba(0.0, 1.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn detects_self_link_cycle() {
+        let mut theme = ThemeColors::default();
+        theme.set_link("loop", "loop");
+        assert!(resolve(&theme, "loop").is_err());
+    }
+
+    #[test]
+    fn detects_multi_node_cycle() {
+        let mut theme = ThemeColors::default();
+        theme.set_link("a", "b");
+        theme.set_link("b", "c");
+        theme.set_link("c", "a");
+        assert!(resolve(&theme, "a").is_err());
+    }
+
+    #[test]
+    fn unknown_key_is_an_error() {
+        let theme = ThemeColors::default();
+        assert!(resolve(&theme, "missing").is_err());
+    }
+}